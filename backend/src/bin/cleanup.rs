@@ -0,0 +1,21 @@
+use allmaptout_backend::cleanup;
+use anyhow::Context;
+use sqlx::postgres::PgPoolOptions;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenvy::dotenv().ok();
+
+    let database_url = std::env::var("DATABASE_URL").context("DATABASE_URL is required")?;
+    cleanup::ensure_safe_target(&database_url)?;
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await?;
+
+    let count = cleanup::truncate_all(&pool).await?;
+    println!("Truncated {} table(s)", count);
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+//! Test database cleanup.
+//!
+//! Shared by the `cleanup` binary and integration tests to reset a database
+//! between runs. Both [`ensure_safe_target`] and [`truncate_all`] refuse to run
+//! with `RUST_ENV=production` or against a database whose name isn't a test
+//! database (`test`, `test_*`, or `*_test`).
+
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use sqlx::{postgres::PgConnectOptions, PgPool};
+
+/// Table managed by sqlx migrations; never truncated.
+const MIGRATIONS_TABLE: &str = "_sqlx_migrations";
+
+/// Ensure the database URL points at a test database.
+pub fn ensure_safe_target(database_url: &str) -> Result<()> {
+    let options = PgConnectOptions::from_str(database_url).context("Invalid DATABASE_URL")?;
+    check_target(
+        options.get_database(),
+        &std::env::var("RUST_ENV").unwrap_or_default(),
+    )
+}
+
+/// Reject production environments and databases not named as test databases.
+fn check_target(database: Option<&str>, rust_env: &str) -> Result<()> {
+    if rust_env == "production" {
+        bail!("Refusing to clean database with RUST_ENV=production");
+    }
+
+    let database = database.unwrap_or_default();
+    let is_test_name =
+        database == "test" || database.starts_with("test_") || database.ends_with("_test");
+
+    if !is_test_name {
+        bail!(
+            "Refusing to clean database '{}': name must be 'test', 'test_*', or '*_test'",
+            database
+        );
+    }
+
+    Ok(())
+}
+
+/// Truncate every table in the `public` schema except the migrations table.
+///
+/// Fails without touching the database unless the pool targets a test database.
+/// Returns the number of tables truncated.
+pub async fn truncate_all(pool: &PgPool) -> Result<usize> {
+    check_target(
+        pool.connect_options().get_database(),
+        &std::env::var("RUST_ENV").unwrap_or_default(),
+    )?;
+
+    let tables: Vec<String> = sqlx::query_scalar(
+        "SELECT tablename FROM pg_tables WHERE schemaname = 'public' AND tablename <> $1",
+    )
+    .bind(MIGRATIONS_TABLE)
+    .fetch_all(pool)
+    .await?;
+
+    if tables.is_empty() {
+        return Ok(0);
+    }
+
+    let table_list = tables
+        .iter()
+        .map(|t| format!("\"{}\"", t.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    sqlx::query(&format!(
+        "TRUNCATE TABLE {} RESTART IDENTITY CASCADE",
+        table_list
+    ))
+    .execute(pool)
+    .await?;
+
+    Ok(tables.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_test_databases() {
+        assert!(check_target(Some("allmaptout_test"), "development").is_ok());
+        assert!(check_target(Some("test_allmaptout"), "").is_ok());
+        assert!(check_target(Some("test"), "").is_ok());
+    }
+
+    #[test]
+    fn rejects_non_test_databases() {
+        assert!(check_target(Some("allmaptout"), "development").is_err());
+        assert!(check_target(Some("app_latest"), "development").is_err());
+        assert!(check_target(Some("contest"), "development").is_err());
+        assert!(check_target(None, "development").is_err());
+    }
+
+    #[test]
+    fn rejects_production() {
+        assert!(check_target(Some("allmaptout_test"), "production").is_err());
+    }
+}
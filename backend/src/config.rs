@@ -1,10 +1,38 @@
-use std::env;
+use std::{env, time::Duration};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 
 pub struct Config {
     pub port: u16,
     pub database_url: String,
+    /// Statements slower than this are logged as warnings. The pool only runs
+    /// startup migrations for now, so runtime queries aren't covered yet.
+    pub slow_query_threshold: Duration,
+    pub mail: MailConfig,
+    /// Tokens that skip rate limiting when sent in the `x-ratelimit-bypass` header.
+    pub rate_limit_bypass_tokens: Vec<String>,
+}
+
+/// Outgoing email settings.
+pub struct MailConfig {
+    /// Sender address, optionally with a display name (`Name <addr>`).
+    pub from: String,
+    pub provider: MailProvider,
+}
+
+/// Email provider selected by `MAIL_PROVIDER`.
+pub enum MailProvider {
+    /// Log messages instead of sending them (development default).
+    Log,
+    Smtp {
+        host: String,
+        port: u16,
+        /// `(username, password)`; both or neither must be configured.
+        credentials: Option<(String, String)>,
+    },
+    Postmark {
+        server_token: String,
+    },
 }
 
 impl Config {
@@ -15,6 +43,148 @@ impl Config {
                 .parse()
                 .context("PORT must be a number")?,
             database_url: env::var("DATABASE_URL").context("DATABASE_URL is required")?,
+            slow_query_threshold: Duration::from_millis(
+                env::var("SLOW_QUERY_THRESHOLD_MS")
+                    .unwrap_or_else(|_| "500".into())
+                    .parse()
+                    .context("SLOW_QUERY_THRESHOLD_MS must be a number")?,
+            ),
+            mail: MailConfig::from_env()?,
+            rate_limit_bypass_tokens: env::var("RATE_LIMIT_BYPASS_TOKENS")
+                .unwrap_or_default()
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect(),
         })
     }
 }
+
+impl MailConfig {
+    pub fn from_env() -> Result<Self> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    /// Build the mail config from a variable lookup, so parsing can be tested
+    /// without touching the process environment.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        // Nothing sends email yet, so `log` is the default everywhere. Require an
+        // explicit provider in production once a feature depends on delivery.
+        let provider = match var("MAIL_PROVIDER")
+            .unwrap_or_else(|| "log".into())
+            .as_str()
+        {
+            "log" => MailProvider::Log,
+            "smtp" => MailProvider::Smtp {
+                host: var("SMTP_HOST").context("SMTP_HOST is required for smtp")?,
+                port: var("SMTP_PORT")
+                    .unwrap_or_else(|| "587".into())
+                    .parse()
+                    .context("SMTP_PORT must be a number")?,
+                credentials: match (var("SMTP_USERNAME"), var("SMTP_PASSWORD")) {
+                    (Some(username), Some(password)) => Some((username, password)),
+                    (None, None) => None,
+                    _ => bail!("SMTP_USERNAME and SMTP_PASSWORD must be set together"),
+                },
+            },
+            "postmark" => MailProvider::Postmark {
+                server_token: var("POSTMARK_SERVER_TOKEN")
+                    .context("POSTMARK_SERVER_TOKEN is required for postmark")?,
+            },
+            other => bail!(
+                "MAIL_PROVIDER must be log, smtp, or postmark (got {})",
+                other
+            ),
+        };
+
+        let from = match provider {
+            MailProvider::Log => var("MAIL_FROM").unwrap_or_else(|| "noreply@localhost".into()),
+            _ => var("MAIL_FROM").context("MAIL_FROM is required")?,
+        };
+        from.parse::<lettre::message::Mailbox>()
+            .context("MAIL_FROM must be a valid email address")?;
+
+        Ok(Self { from, provider })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn mail_config(vars: &[(&str, &str)]) -> Result<MailConfig> {
+        let vars: HashMap<_, _> = vars.iter().copied().collect();
+        MailConfig::from_vars(|name| vars.get(name).map(|v| v.to_string()))
+    }
+
+    #[test]
+    fn defaults_to_log() {
+        let config = mail_config(&[]).unwrap();
+        assert!(matches!(config.provider, MailProvider::Log));
+        assert_eq!(config.from, "noreply@localhost");
+    }
+
+    #[test]
+    fn rejects_invalid_from_address() {
+        let result = mail_config(&[
+            ("MAIL_PROVIDER", "postmark"),
+            ("MAIL_FROM", "not-an-email"),
+            ("POSTMARK_SERVER_TOKEN", "t"),
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_smtp_provider() {
+        let config = mail_config(&[
+            ("MAIL_PROVIDER", "smtp"),
+            ("MAIL_FROM", "noreply@example.com"),
+            ("SMTP_HOST", "smtp.example.com"),
+            ("SMTP_USERNAME", "user"),
+            ("SMTP_PASSWORD", "secret"),
+        ])
+        .unwrap();
+
+        match config.provider {
+            MailProvider::Smtp {
+                host,
+                port,
+                credentials,
+            } => {
+                assert_eq!(host, "smtp.example.com");
+                assert_eq!(port, 587);
+                assert_eq!(credentials, Some(("user".into(), "secret".into())));
+            }
+            _ => panic!("expected smtp provider"),
+        }
+    }
+
+    #[test]
+    fn rejects_partial_smtp_credentials() {
+        let result = mail_config(&[
+            ("MAIL_PROVIDER", "smtp"),
+            ("MAIL_FROM", "noreply@example.com"),
+            ("SMTP_HOST", "smtp.example.com"),
+            ("SMTP_USERNAME", "user"),
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn requires_provider_specific_variables() {
+        assert!(mail_config(&[("MAIL_PROVIDER", "smtp"), ("MAIL_FROM", "a@example.com")]).is_err());
+        assert!(mail_config(&[
+            ("MAIL_PROVIDER", "postmark"),
+            ("MAIL_FROM", "a@example.com")
+        ])
+        .is_err());
+        assert!(mail_config(&[
+            ("MAIL_PROVIDER", "postmark"),
+            ("POSTMARK_SERVER_TOKEN", "t")
+        ])
+        .is_err());
+        assert!(mail_config(&[("MAIL_PROVIDER", "carrier-pigeon")]).is_err());
+    }
+}
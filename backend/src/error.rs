@@ -3,9 +3,8 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use serde::Serialize;
 
-use crate::schemas::{FieldError, ValidationErrorResponse};
+use crate::schemas::{ErrorResponse, FieldError, ValidationErrorResponse};
 
 #[derive(Debug, thiserror::Error)]
 pub enum AppError {
@@ -35,11 +34,6 @@ impl AppError {
     }
 }
 
-#[derive(Serialize)]
-struct ErrorResponse {
-    error: String,
-}
-
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         match self {
@@ -49,25 +43,21 @@ impl IntoResponse for AppError {
             )
                 .into_response(),
             AppError::NotFound(msg) => {
-                (StatusCode::NOT_FOUND, Json(ErrorResponse { error: msg })).into_response()
+                (StatusCode::NOT_FOUND, Json(ErrorResponse::new(msg))).into_response()
             }
             AppError::BadRequest(msg) => {
-                (StatusCode::BAD_REQUEST, Json(ErrorResponse { error: msg })).into_response()
+                (StatusCode::BAD_REQUEST, Json(ErrorResponse::new(msg))).into_response()
             }
             AppError::Unauthorized => (
                 StatusCode::UNAUTHORIZED,
-                Json(ErrorResponse {
-                    error: "Unauthorized".to_string(),
-                }),
+                Json(ErrorResponse::new("Unauthorized")),
             )
                 .into_response(),
             AppError::Internal(err) => {
                 tracing::error!("Internal error: {:?}", err);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: "Internal server error".to_string(),
-                    }),
+                    Json(ErrorResponse::new("Internal server error")),
                 )
                     .into_response()
             }
@@ -75,9 +65,7 @@ impl IntoResponse for AppError {
                 tracing::error!("Database error: {:?}", err);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse {
-                        error: "Internal server error".to_string(),
-                    }),
+                    Json(ErrorResponse::new("Internal server error")),
                 )
                     .into_response()
             }
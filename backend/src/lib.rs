@@ -25,25 +25,66 @@ use tower_http::{
 };
 use tracing::{Level, Span};
 
+pub mod cleanup;
 pub mod config;
 pub mod error;
+pub mod mail;
+pub mod openapi;
 pub mod schemas;
 
 pub use error::{AppError, Result};
 pub use schemas::ValidatedRequest;
 
+/// Header carrying a rate limit bypass token.
+pub const RATE_LIMIT_BYPASS_HEADER: &str = "x-ratelimit-bypass";
+
 /// Returns true if the request has IP headers (external traffic from load balancer)
+///
+/// All external traffic enters through the ingress, which always sets these
+/// headers, and clients can't remove headers the ingress adds. A request
+/// without them therefore came from inside the cluster (kubelet probes,
+/// pod-to-pod calls), so the header-absence check is still trusted.
 fn has_ip_headers(req: &Request) -> bool {
     let headers = req.headers();
     headers.contains_key("x-forwarded-for") || headers.contains_key("x-real-ip")
 }
 
+/// Returns true if the request carries one of the configured bypass tokens
+fn has_bypass_token(req: &Request, tokens: &[String]) -> bool {
+    let Some(provided) = req
+        .headers()
+        .get(RATE_LIMIT_BYPASS_HEADER)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return false;
+    };
+
+    tokens
+        .iter()
+        .any(|token| constant_time_eq(token.as_bytes(), provided.as_bytes()))
+}
+
+/// Compare two byte strings without short-circuiting on the first mismatch
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 #[derive(Serialize, utoipa::ToSchema)]
 pub struct Health {
+    /// Always `ok` when the server is up.
+    #[schema(example = "ok")]
     pub status: String,
 }
 
-#[utoipa::path(get, path = "/health", responses((status = 200, body = Health)))]
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "Server is up", body = Health),
+        (status = 429, description = "Rate limit exceeded (external requests only)")
+    )
+)]
 pub async fn health() -> Json<Health> {
     Json(Health {
         status: "ok".into(),
@@ -67,11 +108,16 @@ fn cors_layer() -> CorsLayer {
     }
 }
 
-pub fn create_router() -> Router {
-    create_router_with_rate_limit(true)
+/// Build the router. `bypass_tokens` exempt requests carrying one of them in
+/// the `x-ratelimit-bypass` header from rate limiting.
+pub fn create_router(bypass_tokens: Vec<String>) -> Router {
+    create_router_with_rate_limit(true, bypass_tokens)
 }
 
-pub fn create_router_with_rate_limit(enable_rate_limit: bool) -> Router {
+pub fn create_router_with_rate_limit(
+    enable_rate_limit: bool,
+    bypass_tokens: Vec<String>,
+) -> Router {
     let governor_config = Arc::new(
         GovernorConfigBuilder::default()
             .per_second(10)
@@ -85,12 +131,14 @@ pub fn create_router_with_rate_limit(enable_rate_limit: bool) -> Router {
     let rate_limit_middleware = {
         let config = governor_config.clone();
         let enabled = enable_rate_limit;
+        let bypass_tokens = Arc::new(bypass_tokens);
         middleware::from_fn(move |req: Request, next: Next| {
             let config = config.clone();
+            let bypass_tokens = bypass_tokens.clone();
             async move {
-                // Skip rate limiting for internal requests (no IP headers)
-                // or if rate limiting is disabled
-                if !enabled || !has_ip_headers(&req) {
+                // Skip rate limiting for internal requests (no IP headers),
+                // requests with a valid bypass token, or if rate limiting is disabled
+                if !enabled || !has_ip_headers(&req) || has_bypass_token(&req, &bypass_tokens) {
                     return next.run(req).await;
                 }
 
@@ -176,8 +224,40 @@ mod tests {
     async fn health_returns_ok() {
         // Set development mode for tests to avoid CORS_ORIGIN requirement
         std::env::set_var("RUST_ENV", "development");
-        let server = TestServer::new(create_router_with_rate_limit(false)).unwrap();
+        let server = TestServer::new(create_router_with_rate_limit(false, Vec::new())).unwrap();
         let response = server.get("/health").await;
         response.assert_status_ok();
     }
+
+    #[tokio::test]
+    async fn bypass_token_skips_rate_limit() {
+        std::env::set_var("RUST_ENV", "development");
+        let server = TestServer::new(create_router(vec!["internal-token".into()])).unwrap();
+
+        for _ in 0..40 {
+            server
+                .get("/health")
+                .add_header("x-forwarded-for", "203.0.113.7")
+                .add_header(RATE_LIMIT_BYPASS_HEADER, "internal-token")
+                .await
+                .assert_status_ok();
+        }
+
+        let mut limited = false;
+        for _ in 0..40 {
+            let response = server
+                .get("/health")
+                .add_header("x-forwarded-for", "203.0.113.7")
+                .add_header(RATE_LIMIT_BYPASS_HEADER, "wrong-token")
+                .await;
+            if response.status_code() == http::StatusCode::TOO_MANY_REQUESTS {
+                limited = true;
+                break;
+            }
+        }
+        assert!(
+            limited,
+            "requests with an invalid token should be rate limited"
+        );
+    }
 }
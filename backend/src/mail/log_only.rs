@@ -0,0 +1,30 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::{EmailMessage, EmailSender};
+
+/// Development sender that logs messages instead of delivering them.
+pub struct LogSender {
+    from: String,
+}
+
+impl LogSender {
+    pub fn new(from: &str) -> Self {
+        Self { from: from.into() }
+    }
+}
+
+#[async_trait]
+impl EmailSender for LogSender {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        tracing::info!(
+            from = %self.from,
+            to = %message.to,
+            subject = %message.subject,
+            // Bodies can contain invite codes and login links; never log them
+            body_len = message.text_body.len(),
+            "Email not sent (log-only mail provider)"
+        );
+        Ok(())
+    }
+}
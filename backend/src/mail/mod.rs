@@ -0,0 +1,64 @@
+//! Outgoing email.
+//!
+//! Handlers send mail through the [`EmailSender`] trait so the provider can be
+//! swapped via configuration:
+//!
+//! - `log` (default): logs messages instead of sending them, for development
+//! - `smtp`: any SMTP relay, using STARTTLS
+//! - `postmark`: the Postmark HTTP API
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::config::{MailConfig, MailProvider};
+
+mod log_only;
+mod postmark;
+mod smtp;
+
+pub use log_only::LogSender;
+pub use postmark::PostmarkSender;
+pub use smtp::SmtpSender;
+
+/// A single outgoing email.
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    /// Recipient address, optionally with a display name (`Name <addr>`).
+    pub to: String,
+    pub subject: String,
+    /// Plain-text body, always sent.
+    pub text_body: String,
+    /// Optional HTML alternative to the plain-text body.
+    pub html_body: Option<String>,
+}
+
+/// Delivers email through a configured provider.
+#[async_trait]
+pub trait EmailSender: Send + Sync {
+    /// Send a single message.
+    async fn send(&self, message: &EmailMessage) -> Result<()>;
+}
+
+/// Build the email sender selected by `MAIL_PROVIDER`.
+pub fn sender_from_config(config: &MailConfig) -> Result<Arc<dyn EmailSender>> {
+    let sender: Arc<dyn EmailSender> = match &config.provider {
+        MailProvider::Log => Arc::new(LogSender::new(&config.from)),
+        MailProvider::Smtp {
+            host,
+            port,
+            credentials,
+        } => Arc::new(SmtpSender::new(
+            &config.from,
+            host,
+            *port,
+            credentials.clone(),
+        )?),
+        MailProvider::Postmark { server_token } => {
+            Arc::new(PostmarkSender::new(&config.from, server_token)?)
+        }
+    };
+
+    Ok(sender)
+}
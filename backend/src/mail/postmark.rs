@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use serde::Serialize;
+
+use super::{EmailMessage, EmailSender};
+
+const POSTMARK_API_URL: &str = "https://api.postmarkapp.com/email";
+
+/// Time allowed to establish a connection to Postmark.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time allowed for a whole send request, so a stalled call can't hang the caller.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Sends email through the Postmark HTTP API.
+pub struct PostmarkSender {
+    from: String,
+    server_token: String,
+    client: reqwest::Client,
+}
+
+impl PostmarkSender {
+    pub fn new(from: &str, server_token: &str) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("Failed to build Postmark HTTP client")?;
+
+        Ok(Self {
+            from: from.into(),
+            server_token: server_token.into(),
+            client,
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct PostmarkEmail<'a> {
+    from: &'a str,
+    to: &'a str,
+    subject: &'a str,
+    text_body: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    html_body: Option<&'a str>,
+}
+
+#[async_trait]
+impl EmailSender for PostmarkSender {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        let response = self
+            .client
+            .post(POSTMARK_API_URL)
+            .header("X-Postmark-Server-Token", &self.server_token)
+            .json(&PostmarkEmail {
+                from: &self.from,
+                to: &message.to,
+                subject: &message.subject,
+                text_body: &message.text_body,
+                html_body: message.html_body.as_deref(),
+            })
+            .send()
+            .await
+            .context("Postmark request failed")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            bail!("Postmark rejected email ({}): {}", status, body);
+        }
+
+        Ok(())
+    }
+}
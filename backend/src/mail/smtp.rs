@@ -0,0 +1,107 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use lettre::{
+    message::{header::ContentType, Mailbox, MultiPart, SinglePart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use super::{EmailMessage, EmailSender};
+
+/// Sends email through an SMTP relay using STARTTLS.
+pub struct SmtpSender {
+    from: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpSender {
+    /// Create a sender for the given relay. `credentials` is `(username, password)`.
+    pub fn new(
+        from: &str,
+        host: &str,
+        port: u16,
+        credentials: Option<(String, String)>,
+    ) -> Result<Self> {
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+            .context("Invalid SMTP_HOST")?
+            .port(port);
+
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Self {
+            from: from
+                .parse()
+                .context("MAIL_FROM must be a valid email address")?,
+            transport: builder.build(),
+        })
+    }
+}
+
+/// Build a MIME message, with an HTML alternative when one is provided.
+fn build_message(from: &Mailbox, message: &EmailMessage) -> Result<Message> {
+    let builder = Message::builder()
+        .from(from.clone())
+        .to(message
+            .to
+            .parse()
+            .with_context(|| format!("Invalid recipient address: {}", message.to))?)
+        .subject(&message.subject);
+
+    let email = match &message.html_body {
+        Some(html) => builder.multipart(MultiPart::alternative_plain_html(
+            message.text_body.clone(),
+            html.clone(),
+        ))?,
+        None => builder.singlepart(
+            SinglePart::builder()
+                .header(ContentType::TEXT_PLAIN)
+                .body(message.text_body.clone()),
+        )?,
+    };
+
+    Ok(email)
+}
+
+#[async_trait]
+impl EmailSender for SmtpSender {
+    async fn send(&self, message: &EmailMessage) -> Result<()> {
+        let email = build_message(&self.from, message)?;
+        self.transport
+            .send(email)
+            .await
+            .context("SMTP delivery failed")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(to: &str) -> EmailMessage {
+        EmailMessage {
+            to: to.into(),
+            subject: "Hello".into(),
+            text_body: "Plain text".into(),
+            html_body: Some("<p>HTML</p>".into()),
+        }
+    }
+
+    #[test]
+    fn builds_multipart_message() {
+        let from: Mailbox = "Wedding <noreply@example.com>".parse().unwrap();
+        let email = build_message(&from, &message("Guest <guest@example.com>")).unwrap();
+        let formatted = String::from_utf8(email.formatted()).unwrap();
+
+        assert!(formatted.contains("multipart/alternative"));
+        assert!(formatted.contains("Subject: Hello"));
+    }
+
+    #[test]
+    fn rejects_invalid_recipient() {
+        let from: Mailbox = "noreply@example.com".parse().unwrap();
+        assert!(build_message(&from, &message("not-an-email")).is_err());
+    }
+}
@@ -1,7 +1,11 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, str::FromStr};
 
 use allmaptout_backend::{config::Config, create_router};
-use sqlx::postgres::PgPoolOptions;
+use anyhow::Context;
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions},
+    ConnectOptions,
+};
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -29,9 +33,15 @@ async fn main() -> anyhow::Result<()> {
 
     // Run database migrations
     info!("Connecting to database...");
+    // Logs slow statements only; the pool currently runs migrations alone.
+    // Route attribution and per-endpoint DB timings are deferred until handlers
+    // receive the pool through router state.
+    let connect_options = PgConnectOptions::from_str(&config.database_url)
+        .context("Invalid DATABASE_URL")?
+        .log_slow_statements(log::LevelFilter::Warn, config.slow_query_threshold);
     let pool = PgPoolOptions::new()
         .max_connections(5)
-        .connect(&config.database_url)
+        .connect_with(connect_options)
         .await?;
 
     info!("Running database migrations...");
@@ -43,7 +53,7 @@ async fn main() -> anyhow::Result<()> {
     info!("Starting server on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, create_router())
+    axum::serve(listener, create_router(config.rate_limit_bypass_tokens))
         .with_graceful_shutdown(shutdown_signal())
         .await?;
 
@@ -0,0 +1,59 @@
+//! OpenAPI document for the API.
+//!
+//! The `openapi` binary prints this document as JSON for frontend client generation.
+//!
+//! Protected endpoints opt into cookie authentication by adding
+//! `security(("cookie_auth" = []))` to their `#[utoipa::path]` attribute.
+
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, SecurityScheme},
+    Modify, OpenApi,
+};
+
+use crate::{
+    schemas::{ErrorResponse, FieldError, ValidationErrorResponse},
+    Health,
+};
+
+/// Name of the cookie that carries the session token.
+pub const SESSION_COOKIE: &str = "session";
+
+/// Name of the security scheme used for cookie-authenticated endpoints.
+pub const COOKIE_AUTH: &str = "cookie_auth";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Wedding API", version = "0.1.0"),
+    paths(crate::health),
+    components(schemas(Health, ErrorResponse, ValidationErrorResponse, FieldError)),
+    modifiers(&SecurityAddon)
+)]
+pub struct ApiDoc;
+
+/// Registers the cookie-based session security scheme.
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            COOKIE_AUTH,
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new(SESSION_COOKIE))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spec_declares_cookie_auth_and_error_schemas() {
+        let spec = ApiDoc::openapi();
+        let components = spec.components.expect("components should be present");
+
+        assert!(components.security_schemes.contains_key(COOKIE_AUTH));
+        assert!(components.schemas.contains_key("ErrorResponse"));
+        assert!(components.schemas.contains_key("ValidationErrorResponse"));
+    }
+}
@@ -54,15 +54,35 @@ impl<T: Validate> ValidatedRequest for T {}
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct FieldError {
     /// The field that failed validation.
+    #[schema(example = "email")]
     pub field: String,
     /// A human-readable error message.
+    #[schema(example = "Invalid email format")]
     pub message: String,
 }
 
+/// Response body for all non-validation errors.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ErrorResponse {
+    /// A human-readable error message.
+    #[schema(example = "Not found")]
+    pub error: String,
+}
+
+impl ErrorResponse {
+    /// Create a new error response with the given message.
+    pub fn new(error: impl Into<String>) -> Self {
+        Self {
+            error: error.into(),
+        }
+    }
+}
+
 /// Response body for validation errors.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ValidationErrorResponse {
     /// The error type.
+    #[schema(example = "Validation failed")]
     pub error: String,
     /// List of field-level validation errors.
     pub fields: Vec<FieldError>,